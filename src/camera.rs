@@ -2,8 +2,45 @@ use bevy::prelude::*;
 use bevy::input::mouse::{MouseWheel,MouseMotion};
 use bevy::render::camera::Projection;
 use bevy::window::{PrimaryWindow, Window};
+#[cfg(feature = "rapier")]
+use bevy_rapier3d::{dynamics::RigidBody, plugin::RapierContext};
+
+#[cfg(feature = "rapier")]
+use crate::picking;
+
+/// Tunable parameters for orbit/pan/zoom input, so feel can be adjusted without recompiling.
+#[derive(Resource)]
+pub struct CameraControlConfig {
+    /// Mouse button held to orbit.
+    pub orbit_button: MouseButton,
+    /// Mouse button held to pan.
+    pub pan_button: MouseButton,
+    /// Scales the raw scroll wheel delta before it's accumulated as zoom input.
+    pub scroll_sensitivity: f32,
+    /// Scales accumulated pan/orbit mouse motion before it's accumulated onto the camera.
+    pub pan_orbit_gain: f32,
+    /// Closest the camera is allowed to zoom in to its focus point.
+    pub min_radius: f32,
+    /// Farthest the camera is allowed to zoom out from its focus point.
+    pub max_radius: f32,
+    /// Time constant (seconds) for the exponential smoothing applied to orbit/pan/zoom input.
+    pub smoothing_tau: f32,
+}
+
+impl Default for CameraControlConfig {
+    fn default() -> Self {
+        CameraControlConfig {
+            orbit_button: MouseButton::Right,
+            pan_button: MouseButton::Middle,
+            scroll_sensitivity: 0.2,
+            pan_orbit_gain: 2.0,
+            min_radius: 0.05,
+            max_radius: 100.0,
+            smoothing_tau: 0.1,
+        }
+    }
+}
 
-const LERP: f32 = 0.1;
 // ANCHOR: example
 /// Tags an entity as capable of panning and orbiting.
 #[derive(Component)]
@@ -17,6 +54,13 @@ pub struct PanOrbitCamera {
     pub rotation_move: Vec2,
     pub scroll: f32,
     pub orbit_button_changed: bool,
+    /// When set, `focus` is slaved to this entity's translation each frame instead of being
+    /// free-floating, so the camera keeps it centered (e.g. after dragging it).
+    pub follow_target: Option<Entity>,
+    /// Reference "up" used to keep the horizon level while following a target; smoothly
+    /// tracks the target's own up vector so the camera banks the way a chase camera does
+    /// instead of staying locked to world-up.
+    pub up: Vec3,
 }
 
 impl Default for PanOrbitCamera {
@@ -30,6 +74,8 @@ impl Default for PanOrbitCamera {
             rotation_move: Vec2::ZERO,
             scroll: 0.0,
             orbit_button_changed: false,
+            follow_target: None,
+            up: Vec3::Y,
         }
     }
 }
@@ -38,6 +84,7 @@ pub fn accumulate_mouse_events_system(
     mut ev_motion: EventReader<MouseMotion>,
     mut ev_scroll: EventReader<MouseWheel>,
     input_mouse: Res<ButtonInput<MouseButton>>,
+    config: Res<CameraControlConfig>,
     mut query: Query<&mut PanOrbitCamera>,
 ) {
     // need to accumulate these and apply them to all cameras
@@ -45,15 +92,12 @@ pub fn accumulate_mouse_events_system(
     let mut rotation_move = Vec2::ZERO;
     let mut scroll = 0.0;
     let mut orbit_button_changed = false;
-    
-    let orbit_button = MouseButton::Right;
-    let pan_button = MouseButton::Middle;
 
-    if input_mouse.pressed(orbit_button) {
+    if input_mouse.pressed(config.orbit_button) {
         for ev in ev_motion.read() {
             rotation_move += ev.delta;
         }
-    } else if input_mouse.pressed(pan_button) {
+    } else if input_mouse.pressed(config.pan_button) {
         // Pan only if we're not rotating at the moment
         for ev in ev_motion.read() {
             pan += ev.delta;
@@ -62,15 +106,15 @@ pub fn accumulate_mouse_events_system(
     for ev in ev_scroll.read() {
         scroll += ev.y;
     }
-    if input_mouse.just_released(orbit_button) || input_mouse.just_pressed(orbit_button) {
+    if input_mouse.just_released(config.orbit_button) || input_mouse.just_pressed(config.orbit_button) {
         orbit_button_changed = true;
     }
 
     for mut camera in query.iter_mut() {
         camera.orbit_button_changed |= orbit_button_changed;
-        camera.pan += 2.0 * pan;
-        camera.rotation_move += 2.0 * rotation_move;
-        camera.scroll += 2.0 * scroll;
+        camera.pan += config.pan_orbit_gain * pan;
+        camera.rotation_move += config.pan_orbit_gain * rotation_move;
+        camera.scroll += config.scroll_sensitivity * scroll;
     }
 
     ev_motion.clear();
@@ -78,23 +122,45 @@ pub fn accumulate_mouse_events_system(
 
 /// Pan the camera with middle mouse click, zoom with scroll wheel, orbit with right mouse click.
 pub fn update_camera_system(
+    time: Res<Time>,
+    config: Res<CameraControlConfig>,
     windows: Query<&Window, With<PrimaryWindow>>,
+    targets: Query<&Transform, Without<PanOrbitCamera>>,
     mut query: Query<(&mut PanOrbitCamera, &mut Transform, &Projection)>,
 ) {
+    // Exponential smoothing so orbit/pan/zoom feel is identical regardless of framerate:
+    // at dt = tau this consumes ~63% of the remaining input per frame, same as the old
+    // per-frame lerp did at 60fps, but it no longer drifts at other framerates.
+    let alpha = 1.0 - (-time.delta_seconds() / config.smoothing_tau).exp();
+
     for (mut camera, mut transform, projection) in query.iter_mut() {
+        let mut any = false;
+
+        if let Some(target) = camera.follow_target {
+            match targets.get(target) {
+                Ok(target_transform) => {
+                    let focus = camera.focus;
+                    camera.focus = focus.lerp(target_transform.translation, alpha);
+                    let up = camera.up;
+                    camera.up = up.lerp(*target_transform.up(), alpha).normalize_or_zero();
+                    any = true;
+                }
+                Err(_) => camera.follow_target = None,
+            }
+        }
+
         if camera.orbit_button_changed {
             // only check for upside down when orbiting started or ended this frame
             // if the camera is "upside" down, panning horizontally would be inverted, so invert the input to make it correct
             let up = transform.rotation * Vec3::Y;
             camera.upside_down = up.y <= 0.0;
-            
+
             camera.orbit_button_changed = false;
         }
 
-        let mut any = false;
         if camera.rotation_move.length_squared() > 0.5 {
             any = true;
-            let rotation_move = camera.rotation_move * LERP;
+            let rotation_move = camera.rotation_move * alpha;
             camera.rotation_move -= rotation_move;
 
             let window = get_primary_window_size(&windows);
@@ -111,7 +177,7 @@ pub fn update_camera_system(
         
         if camera.pan.length_squared() > 0.5 {
             any = true;
-            let mut pan = camera.pan * LERP;
+            let mut pan = camera.pan * alpha;
             camera.pan -= pan;
             // make panning distance independent of resolution and FOV,
             let window = get_primary_window_size(&windows);
@@ -128,12 +194,12 @@ pub fn update_camera_system(
         
         if camera.scroll.abs() > 0.5 {
             any = true;
-            
-            let scroll = camera.scroll * LERP;
+
+            let scroll = camera.scroll * alpha;
             camera.scroll -= scroll;
             camera.radius -= scroll * camera.radius * 0.05;
-            // dont allow zoom to reach zero or you get stuck
-            camera.radius = f32::max(camera.radius, 0.05);
+            // dont allow zoom to reach zero or past the far plane, or you get stuck
+            camera.radius = camera.radius.clamp(config.min_radius, config.max_radius);
         }
 
         if any {
@@ -143,6 +209,20 @@ pub fn update_camera_system(
             let rot_matrix = Mat3::from_quat(transform.rotation);
             transform.translation = camera.focus + rot_matrix.mul_vec3(Vec3::new(0.0, 0.0, camera.radius));
         }
+
+        if camera.follow_target.is_some() {
+            // Re-level roll against the (smoothed) target up vector, chase-cam style, without
+            // touching the user-driven yaw/pitch baked into `transform.rotation` above.
+            let forward = camera.focus - transform.translation;
+            if forward.length_squared() > 1e-6 && !forward.normalize().abs_diff_eq(camera.up, 1e-3) {
+                let leveled = Transform::from_translation(transform.translation)
+                    .looking_at(camera.focus, camera.up)
+                    .rotation;
+                transform.rotation = transform.rotation.slerp(leveled, alpha);
+                let rot_matrix = Mat3::from_quat(transform.rotation);
+                transform.translation = camera.focus + rot_matrix.mul_vec3(Vec3::new(0.0, 0.0, camera.radius));
+            }
+        }
     }
 
     // consume any remaining events, so they don't pile up if we don't need them
@@ -154,3 +234,28 @@ fn get_primary_window_size(windows: &Query<&Window, With<PrimaryWindow>>) -> Vec
     let window = windows.get_single().unwrap();
     Vec2::new(window.width(), window.height())
 }
+
+/// `F` ray-casts from the cursor through whichever camera is currently active (via
+/// `picking::active_camera_cursor_ray`, the same helper `dragging::start_drag_system` uses) and
+/// sets the `PanOrbitCamera`'s `follow_target` to the dynamic rigid body it hits, or clears it
+/// back to free orbit if the ray hits nothing. Rapier-only: picking a dynamic rigid body isn't
+/// wired up for the `avian` backend yet.
+#[cfg(feature = "rapier")]
+pub fn select_follow_target_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    rapier_context: Res<RapierContext>,
+    rigid_bodies: Query<&RigidBody>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut pan_orbit: Query<&mut PanOrbitCamera>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+    let Some((ray, _)) = picking::active_camera_cursor_ray(&windows, &cameras) else { return };
+    let Ok(mut pan_orbit) = pan_orbit.get_single_mut() else { return };
+
+    let hit = picking::cast_ray_for_dynamic_body(&rapier_context, &rigid_bodies, ray);
+
+    pan_orbit.follow_target = hit.map(|(entity, _)| entity);
+}
@@ -0,0 +1,146 @@
+use crate::camera::PanOrbitCamera;
+use crate::picking;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_rapier3d::{
+    dynamics::{ExternalForce, ReadMassProperties, RigidBody, Velocity},
+    plugin::RapierContext,
+};
+
+/// Tuning for the critically-damped spring used to drag rigid bodies with the mouse.
+/// Exposed as a resource so heavy and light bodies can be given different feel without
+/// recompiling (e.g. by swapping it out per-scene). Damping isn't configured directly here:
+/// it's derived per-body from `stiffness` and the grabbed body's own mass (`c = 2*sqrt(k*m)`)
+/// so the spring is actually critically damped for both the light and heavy cube, rather than
+/// one fixed damping value being critical for neither.
+#[derive(Resource)]
+pub struct DragConfig {
+    /// Spring stiffness pulling the grabbed body toward the cursor target.
+    pub stiffness: f32,
+    /// Upper bound on the magnitude of the applied spring force.
+    pub max_force: f32,
+}
+
+impl Default for DragConfig {
+    fn default() -> Self {
+        DragConfig {
+            stiffness: 40.0,
+            max_force: 50.0,
+        }
+    }
+}
+
+/// The body currently being dragged, if any.
+#[derive(Resource, Default)]
+pub struct DragState {
+    grabbed: Option<Grabbed>,
+}
+
+struct Grabbed {
+    entity: Entity,
+    /// Point and normal of the drag plane, fixed at grab time: the plane through the hit
+    /// point, perpendicular to the camera's forward vector.
+    plane_point: Vec3,
+    plane_normal: Vec3,
+    /// Offset from the point on the drag plane to the body's center, held constant for the
+    /// duration of the drag so the body doesn't snap to the cursor.
+    grab_offset: Vec3,
+}
+
+/// On left-click, ray-casts from the cursor through whichever camera is currently active
+/// (the `PanOrbitCamera`, or a glTF camera selected via `C`) and grabs the nearest dynamic
+/// rigid body the ray hits. The same hit also becomes the `PanOrbitCamera`'s follow target
+/// (cleared on a miss), so clicking a body doubles as selecting it without a second ray-cast.
+#[allow(clippy::too_many_arguments)]
+pub fn start_drag_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    rapier_context: Res<RapierContext>,
+    rigid_bodies: Query<&RigidBody>,
+    transforms: Query<&Transform>,
+    mut drag_state: ResMut<DragState>,
+    mut pan_orbit: Query<&mut PanOrbitCamera>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Some((ray, camera_transform)) = picking::active_camera_cursor_ray(&windows, &cameras)
+    else {
+        return;
+    };
+    let origin = ray.origin;
+    let direction = *ray.direction;
+
+    let hit = picking::cast_ray_for_dynamic_body(&rapier_context, &rigid_bodies, ray);
+
+    if let Ok(mut pan_orbit) = pan_orbit.get_single_mut() {
+        pan_orbit.follow_target = hit.map(|(entity, _)| entity);
+    }
+
+    let Some((entity, toi)) = hit else { return };
+    let hit_point = origin + direction * toi;
+    let body_translation = transforms.get(entity).map_or(hit_point, |t| t.translation);
+    drag_state.grabbed = Some(Grabbed {
+        entity,
+        plane_point: hit_point,
+        plane_normal: camera_transform.forward(),
+        grab_offset: body_translation - hit_point,
+    });
+}
+
+/// Clears the current grab on left-button release.
+pub fn release_drag_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    mut drag_state: ResMut<DragState>,
+) {
+    if mouse_button.just_released(MouseButton::Left) {
+        drag_state.grabbed = None;
+    }
+}
+
+/// While a body is grabbed, projects the cursor ray onto the drag plane each physics step and
+/// pushes the body toward that point with a critically-damped spring. All other bodies have
+/// their drag force cleared.
+pub fn apply_drag_force_system(
+    drag_config: Res<DragConfig>,
+    drag_state: Res<DragState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut bodies: Query<(Entity, &Transform, &Velocity, &ReadMassProperties, &mut ExternalForce)>,
+) {
+    let target = drag_state.grabbed.as_ref().and_then(|grabbed| {
+        let (ray, _) = picking::active_camera_cursor_ray(&windows, &cameras)?;
+        let origin = ray.origin;
+        let direction = *ray.direction;
+        let distance =
+            ray_plane_intersection(origin, direction, grabbed.plane_point, grabbed.plane_normal)?;
+        Some((grabbed.entity, origin + direction * distance + grabbed.grab_offset))
+    });
+
+    for (entity, transform, velocity, mass_properties, mut force) in bodies.iter_mut() {
+        force.force = match target {
+            Some((target_entity, target_point)) if target_entity == entity => {
+                let critical_damping =
+                    2.0 * (drag_config.stiffness * mass_properties.get().mass).sqrt();
+                let spring = drag_config.stiffness * (target_point - transform.translation)
+                    - critical_damping * velocity.linvel;
+                spring.clamp_length_max(drag_config.max_force)
+            }
+            _ => Vec3::ZERO,
+        };
+    }
+}
+
+fn ray_plane_intersection(
+    origin: Vec3,
+    direction: Vec3,
+    plane_point: Vec3,
+    plane_normal: Vec3,
+) -> Option<f32> {
+    let denom = direction.dot(plane_normal);
+    if denom.abs() < 1e-6 {
+        return None;
+    }
+    Some((plane_point - origin).dot(plane_normal) / denom)
+}
@@ -0,0 +1,86 @@
+use bevy::core_pipeline::Skybox;
+use bevy::prelude::*;
+use bevy::render::render_resource::{TextureViewDescriptor, TextureViewDimension};
+use bevy::window::{CursorGrabMode, PrimaryWindow};
+
+/// Path to the cubemap used for the skybox: six faces stacked vertically into one image, the
+/// layout Bevy's own skybox example expects before it's reinterpreted as a cube array. Ships
+/// with a small flat-color placeholder under `assets/environment_maps/skybox.png`; drop in a
+/// real six-face cubemap at the same path and size ratio (width × width*6) to replace it.
+const SKYBOX_IMAGE: &str = "environment_maps/skybox.png";
+
+/// Tracks the skybox image handle until it has finished loading, since it needs a one-time
+/// reinterpretation from a plain 2D texture into a cube array.
+#[derive(Resource)]
+pub(crate) struct Cubemap {
+    image: Handle<Image>,
+    loaded: bool,
+}
+
+pub fn setup_skybox(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(Cubemap {
+        image: asset_server.load(SKYBOX_IMAGE),
+        loaded: false,
+    });
+}
+
+/// Once the skybox image finishes loading, reinterprets it as a cube texture, then attaches a
+/// `Skybox` to every 3D camera that doesn't have one yet, including ones registered later (e.g.
+/// a glTF camera pulled in by `scene_loader::register_scene_cameras_system` after a model is
+/// dropped post-load) rather than only the cameras that existed at the moment the image loaded.
+pub fn attach_skybox_system(
+    mut commands: Commands,
+    mut cubemap: ResMut<Cubemap>,
+    mut images: ResMut<Assets<Image>>,
+    mut asset_events: EventReader<AssetEvent<Image>>,
+    unskyboxed_cameras: Query<Entity, (With<Camera3d>, Without<Skybox>)>,
+) {
+    if !cubemap.loaded {
+        for event in asset_events.read() {
+            if !event.is_loaded_with_dependencies(&cubemap.image) {
+                continue;
+            }
+            let image = images.get_mut(&cubemap.image).unwrap();
+            if image.texture_descriptor.array_layer_count() == 1 {
+                image.reinterpret_stacked_2d_as_array(image.height() / image.width());
+                image.texture_view_descriptor = Some(TextureViewDescriptor {
+                    dimension: Some(TextureViewDimension::Cube),
+                    ..default()
+                });
+            }
+            cubemap.loaded = true;
+        }
+    }
+
+    if !cubemap.loaded {
+        return;
+    }
+    for camera in unskyboxed_cameras.iter() {
+        commands.entity(camera).insert(Skybox {
+            image: cubemap.image.clone(),
+            brightness: 1000.0,
+        });
+    }
+}
+
+/// `L` locks and hides the cursor so the `PanOrbitCamera` can be free-looked without dragging
+/// the window edge; pressing it again restores the normal cursor.
+pub fn toggle_cursor_grab_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyL) {
+        return;
+    }
+    let Ok(mut window) = windows.get_single_mut() else { return };
+    match window.cursor.grab_mode {
+        CursorGrabMode::None => {
+            window.cursor.grab_mode = CursorGrabMode::Locked;
+            window.cursor.visible = false;
+        }
+        _ => {
+            window.cursor.grab_mode = CursorGrabMode::None;
+            window.cursor.visible = true;
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use avian3d::prelude::{
+    AngularDamping, Collider, Gravity, LinearDamping, Mass, PhysicsPlugins, RigidBody,
+    SubstepCount,
+};
+use bevy::prelude::*;
+
+/// Installs Avian with a fixed physics timestep and substep count equivalent to Rapier's
+/// `TimestepMode::Fixed { dt: 0.05, substeps: 20 }`.
+pub fn configure_app(app: &mut App) {
+    app.insert_resource(Time::<Fixed>::from_seconds(0.05))
+        .insert_resource(Gravity(Vec3::new(0.0, -9.81, 0.0)))
+        .insert_resource(SubstepCount(20))
+        .add_plugins(PhysicsPlugins::default());
+}
+
+/// Spawns a static (no `RigidBody`) collider, e.g. a wall or floor slab, sized with full
+/// `size` side lengths the same way `spawn_dynamic_box` is.
+pub fn spawn_static_collider(commands: &mut Commands, size: Vec3) -> Entity {
+    commands.spawn(Collider::cuboid(size.x, size.y, size.z)).id()
+}
+
+/// Spawns a dynamic box using Avian's native rigid-body/collider/damping components. The
+/// caller is still responsible for adding visual (`Handle<Mesh>`, `Handle<StandardMaterial>`)
+/// components; Avian has no drag-force equivalent wired up yet.
+pub fn spawn_dynamic_box(
+    commands: &mut Commands,
+    size: Vec3,
+    mass: f32,
+    damping: f32,
+    transform: Transform,
+) -> Entity {
+    commands
+        .spawn((
+            Collider::cuboid(size.x, size.y, size.z),
+            RigidBody::Dynamic,
+            Mass(mass),
+            LinearDamping(damping),
+            AngularDamping(damping),
+            SpatialBundle::from_transform(transform),
+        ))
+        .id()
+}
@@ -0,0 +1,18 @@
+//! Thin seam between the scene setup in `main.rs` and whichever physics engine is compiled
+//! in, so the same scene can be run on either Rapier or Avian3d to compare solver behavior
+//! and drag response. Select the backend with the `rapier` (default) or `avian` Cargo feature.
+
+#[cfg(all(feature = "rapier", feature = "avian"))]
+compile_error!("enable exactly one of the \"rapier\" or \"avian\" features, not both");
+#[cfg(not(any(feature = "rapier", feature = "avian")))]
+compile_error!("enable one of the \"rapier\" or \"avian\" features");
+
+#[cfg(feature = "avian")]
+mod avian_backend;
+#[cfg(feature = "avian")]
+pub use avian_backend::{configure_app, spawn_dynamic_box, spawn_static_collider};
+
+#[cfg(feature = "rapier")]
+mod rapier_backend;
+#[cfg(feature = "rapier")]
+pub use rapier_backend::{configure_app, spawn_dynamic_box, spawn_static_collider};
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy_rapier3d::{
+    dynamics::{Damping, RigidBody},
+    geometry::{Collider, ColliderMassProperties},
+    plugin::{NoUserData, RapierConfiguration, RapierPhysicsPlugin, TimestepMode},
+};
+
+/// Installs the Rapier plugin with the testbed's fixed 20-substep timestep.
+pub fn configure_app(app: &mut App) {
+    app.insert_resource(RapierConfiguration {
+        timestep_mode: TimestepMode::Fixed { dt: 0.05, substeps: 20 },
+        physics_pipeline_active: true,
+        query_pipeline_active: true,
+        gravity: Vec3::new(0.0, -9.81, 0.0),
+        ..default()
+    })
+    .add_plugins(RapierPhysicsPlugin::<NoUserData>::default());
+}
+
+/// Spawns a static (no `RigidBody`) collider, e.g. a wall or floor slab, sized with full
+/// `size` side lengths the same way `spawn_dynamic_box` is.
+pub fn spawn_static_collider(commands: &mut Commands, size: Vec3) -> Entity {
+    commands.spawn(Collider::cuboid(size.x * 0.5, size.y * 0.5, size.z * 0.5)).id()
+}
+
+/// Spawns a dynamic box using Rapier's native rigid-body/collider/damping components. The
+/// caller is still responsible for adding visual (`Handle<Mesh>`, `Handle<StandardMaterial>`)
+/// and drag (`Velocity`, `ExternalForce`) components.
+pub fn spawn_dynamic_box(
+    commands: &mut Commands,
+    size: Vec3,
+    mass: f32,
+    damping: f32,
+    transform: Transform,
+) -> Entity {
+    commands
+        .spawn((
+            Collider::cuboid(size.x * 0.5, size.y * 0.5, size.z * 0.5),
+            RigidBody::Dynamic,
+            ColliderMassProperties::Mass(mass),
+            Damping { linear_damping: damping, angular_damping: damping },
+            SpatialBundle::from_transform(transform),
+        ))
+        .id()
+}
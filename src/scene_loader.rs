@@ -0,0 +1,171 @@
+use bevy::gltf::Gltf;
+use bevy::hierarchy::HierarchyQueryExt;
+use bevy::prelude::*;
+use bevy::scene::Scene;
+use bevy::window::FileDragAndDrop;
+#[cfg(feature = "rapier")]
+use bevy_rapier3d::{
+    dynamics::{Damping, ExternalForce, ReadMassProperties, RigidBody, Velocity},
+    geometry::{Collider, ComputedColliderShape},
+};
+
+/// Every camera the user can currently switch to, in cycle order. Index 0 is always the
+/// built-in `PanOrbitCamera`; cameras defined inside a loaded glTF are appended as they're
+/// discovered in the spawned scene graph.
+#[derive(Resource)]
+pub struct CameraCycle {
+    pub cameras: Vec<Entity>,
+    pub active: usize,
+}
+
+impl CameraCycle {
+    pub fn new(pan_orbit_camera: Entity) -> Self {
+        CameraCycle { cameras: vec![pan_orbit_camera], active: 0 }
+    }
+}
+
+/// A glTF asset that is still loading; once it and its mesh data are available we spawn it as
+/// a draggable rigid body.
+#[derive(Component)]
+pub(crate) struct PendingGltf(Handle<Gltf>);
+
+/// Marks the root entity of a scene that was loaded at runtime, so `register_scene_cameras_system`
+/// knows which newly-spawned cameras belong to it.
+#[derive(Component)]
+pub(crate) struct LoadedSceneRoot;
+
+/// Drop a `.gltf`/`.glb` file onto the window to load it as a new draggable physics object.
+pub fn handle_file_drop_system(
+    mut commands: Commands,
+    mut events: EventReader<FileDragAndDrop>,
+    asset_server: Res<AssetServer>,
+) {
+    for event in events.read() {
+        if let FileDragAndDrop::DroppedFile { path_buf, .. } = event {
+            let handle: Handle<Gltf> = asset_server.load(path_buf.clone());
+            commands.spawn(PendingGltf(handle));
+        }
+    }
+}
+
+/// Once a dropped glTF has finished loading, spawns its default scene and builds a compound
+/// collider out of a convex hull for every mesh the scene contains, placed at that mesh entity's
+/// actual offset/rotation in the spawned scene's own entity hierarchy, so a multi-part model
+/// collides as a whole rather than just its first piece stacked at the origin. If no mesh yields
+/// a usable hull, the glTF is skipped entirely rather than spawned as a collider-less (massless,
+/// NaN-prone) dynamic body. Rapier-only: a dropped glTF just sits as a `PendingGltf` under the
+/// `avian` backend until this grows an Avian collider-building path of its own.
+#[cfg(feature = "rapier")]
+pub fn spawn_loaded_gltf_system(
+    mut commands: Commands,
+    gltf_assets: Res<Assets<Gltf>>,
+    scene_assets: Res<Assets<Scene>>,
+    meshes: Res<Assets<Mesh>>,
+    pending: Query<(Entity, &PendingGltf)>,
+) {
+    for (pending_entity, PendingGltf(handle)) in pending.iter() {
+        let Some(gltf) = gltf_assets.get(handle) else { continue };
+        let Some(scene_handle) = gltf.scenes.first() else {
+            commands.entity(pending_entity).despawn();
+            continue;
+        };
+        let Some(scene) = scene_assets.get(scene_handle) else { continue };
+
+        let hulls = collect_mesh_hulls(&scene.world, &meshes);
+        if hulls.is_empty() {
+            commands.entity(pending_entity).despawn();
+            continue;
+        }
+
+        commands.spawn((
+            SceneBundle { scene: scene_handle.clone(), ..default() },
+            RigidBody::Dynamic,
+            Collider::compound(hulls),
+            Damping { linear_damping: 0.1, angular_damping: 0.1 },
+            Velocity::default(),
+            ExternalForce::default(),
+            ReadMassProperties::default(),
+            LoadedSceneRoot,
+        ));
+
+        commands.entity(pending_entity).despawn();
+    }
+}
+
+/// Walks every entity in a loaded glTF scene's own `World`, and for each one carrying a mesh,
+/// pushes a compound-collider entry at that entity's accumulated world transform (its own
+/// `Transform` composed with every `Parent` above it, since the scene isn't spawned into the
+/// main `World` yet and so has no `GlobalTransform` computed for it).
+#[cfg(feature = "rapier")]
+fn collect_mesh_hulls(
+    scene_world: &World,
+    meshes: &Assets<Mesh>,
+) -> Vec<(Vec3, Quat, Collider)> {
+    let mut hulls = Vec::new();
+    for entity in scene_world.iter_entities() {
+        let Some(mesh_handle) = entity.get::<Handle<Mesh>>() else { continue };
+        let Some(mesh) = meshes.get(mesh_handle) else { continue };
+        let Some(hull) = Collider::from_bevy_mesh(mesh, &ComputedColliderShape::ConvexHull) else {
+            continue;
+        };
+        let transform = scene_world_transform(scene_world, entity.id());
+        hulls.push((transform.translation, transform.rotation, hull));
+    }
+    hulls
+}
+
+/// Composes an entity's `Transform` with every ancestor's, walking up `Parent` links within a
+/// scene's own (not-yet-spawned) `World`.
+#[cfg(feature = "rapier")]
+fn scene_world_transform(scene_world: &World, entity: Entity) -> Transform {
+    let mut transform = scene_world.get::<Transform>(entity).copied().unwrap_or_default();
+    let mut current = entity;
+    while let Some(parent) = scene_world.get::<Parent>(current) {
+        let parent_transform = scene_world.get::<Transform>(parent.get()).copied().unwrap_or_default();
+        transform = parent_transform * transform;
+        current = parent.get();
+    }
+    transform
+}
+
+/// Bevy spawns a glTF's own cameras as part of the scene graph after it loads; pick up any
+/// such camera under a `LoadedSceneRoot` and add it to the cycle, inactive until selected.
+pub fn register_scene_cameras_system(
+    mut camera_cycle: ResMut<CameraCycle>,
+    new_cameras: Query<Entity, Added<Camera>>,
+    scene_roots: Query<Entity, With<LoadedSceneRoot>>,
+    parents: Query<&Parent>,
+    mut cameras: Query<&mut Camera>,
+) {
+    for camera_entity in new_cameras.iter() {
+        let belongs_to_loaded_scene = scene_roots
+            .iter()
+            .any(|root| parents.iter_ancestors(camera_entity).any(|ancestor| ancestor == root));
+        if !belongs_to_loaded_scene || camera_cycle.cameras.contains(&camera_entity) {
+            continue;
+        }
+        if let Ok(mut camera) = cameras.get_mut(camera_entity) {
+            camera.is_active = false;
+        }
+        camera_cycle.cameras.push(camera_entity);
+    }
+}
+
+/// Pressing `C` advances to the next camera in the cycle, wrapping back to the pan-orbit
+/// controller, and toggles `Camera::is_active` so only one camera renders at a time.
+pub fn cycle_camera_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut camera_cycle: ResMut<CameraCycle>,
+    mut cameras: Query<&mut Camera>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyC) || camera_cycle.cameras.len() < 2 {
+        return;
+    }
+    if let Ok(mut camera) = cameras.get_mut(camera_cycle.cameras[camera_cycle.active]) {
+        camera.is_active = false;
+    }
+    camera_cycle.active = (camera_cycle.active + 1) % camera_cycle.cameras.len();
+    if let Ok(mut camera) = cameras.get_mut(camera_cycle.cameras[camera_cycle.active]) {
+        camera.is_active = true;
+    }
+}